@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use aleo_account::{Address as AddressNative, PrivateKey as PrivateKeyNative, ViewKey as ViewKeyNative};
+
+use std::{convert::TryFrom, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct Address {
+    pub(crate) address: AddressNative,
+}
+
+#[wasm_bindgen]
+impl Address {
+    #[wasm_bindgen]
+    pub fn from_string(address: &str) -> Self {
+        let address = AddressNative::from_str(address).unwrap();
+
+        Self { address }
+    }
+
+    #[wasm_bindgen]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.address.to_string()
+    }
+}
+
+impl Address {
+    pub(crate) fn from_private_key(private_key: &PrivateKeyNative) -> Self {
+        Self { address: AddressNative::try_from(private_key).unwrap() }
+    }
+
+    pub(crate) fn from_view_key(view_key: &ViewKeyNative) -> Self {
+        Self { address: AddressNative::try_from(view_key).unwrap() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+
+    use wasm_bindgen_test::*;
+
+    const ITERATIONS: u64 = 1_000;
+
+    #[wasm_bindgen_test]
+    pub fn test_address_from_string() {
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::new();
+            let expected = private_key.to_address();
+
+            assert_eq!(expected.to_string(), Address::from_string(&expected.to_string()).to_string());
+        }
+    }
+}
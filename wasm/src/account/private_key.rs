@@ -14,12 +14,124 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
 
-use aleo_account::{Address, PrivateKey as PrivateKeyNative, RecordCiphertext, ViewKey};
+use crate::{Address, ViewKey};
 
-use rand::{rngs::StdRng, SeedableRng};
+use aleo_account::{
+    Address as AddressNative, Field, FromBytes, PrivateKey as PrivateKeyNative, Signature, ToBytes, ToFields, Value,
+};
+
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Nonce,
+};
+use argon2::{Algorithm, Argon2, Params, Version};
+use blake2::{Blake2s256, Digest};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
 use std::{convert::TryFrom, str::FromStr};
 use wasm_bindgen::prelude::*;
 
+/// Byte lengths of the components making up a [`PrivateKeyCiphertext`].
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Argon2id cost parameters pinned for this ciphertext format. These are fixed (rather
+/// than `Argon2::default()`) so the at-rest format doesn't silently change across
+/// `argon2` crate versions and existing ciphertexts remain decryptable.
+const ARGON2_MEM_COST_KIB: u32 = 19 * 1024;
+const ARGON2_TIME_COST: u32 = 2;
+const ARGON2_PARALLELISM: u32 = 1;
+const ARGON2_OUTPUT_LEN: usize = 32;
+
+/// The self-describing, password-encrypted serialization of a [`PrivateKey`].
+///
+/// Serialized as `salt || nonce || ciphertext`, where `ciphertext` includes the
+/// AEAD authentication tag appended by `Aes256Gcm`, and the whole byte string is
+/// hex-encoded for safe storage in browser local storage.
+struct PrivateKeyCiphertext {
+    salt: [u8; SALT_LEN],
+    nonce: [u8; NONCE_LEN],
+    ciphertext: Vec<u8>,
+}
+
+impl PrivateKeyCiphertext {
+    /// Derives a 256-bit symmetric key from `secret` and `salt` using Argon2id, with
+    /// cost parameters pinned via [`ARGON2_MEM_COST_KIB`]/[`ARGON2_TIME_COST`]/[`ARGON2_PARALLELISM`].
+    fn derive_key(secret: &str, salt: &[u8]) -> [u8; 32] {
+        let params =
+            Params::new(ARGON2_MEM_COST_KIB, ARGON2_TIME_COST, ARGON2_PARALLELISM, Some(ARGON2_OUTPUT_LEN)).unwrap();
+        let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+
+        let mut key = [0u8; 32];
+        argon2.hash_password_into(secret.as_bytes(), salt, &mut key).unwrap();
+        key
+    }
+
+    /// Encrypts `plaintext` (the private key's canonical string encoding) under `secret`.
+    fn encrypt(secret: &str, plaintext: &[u8]) -> Self {
+        let mut rng = StdRng::from_entropy();
+
+        let mut salt = [0u8; SALT_LEN];
+        rng.fill_bytes(&mut salt);
+        let mut nonce = [0u8; NONCE_LEN];
+        rng.fill_bytes(&mut nonce);
+
+        let key = Self::derive_key(secret, &salt);
+        let cipher = Aes256Gcm::new_from_slice(&key).unwrap();
+        let ciphertext = cipher.encrypt(Nonce::from_slice(&nonce), plaintext).unwrap();
+
+        Self { salt, nonce, ciphertext }
+    }
+
+    /// Decrypts this ciphertext under `secret`, failing if the password is wrong.
+    fn decrypt(&self, secret: &str) -> Result<Vec<u8>, String> {
+        let key = Self::derive_key(secret, &self.salt);
+        let cipher = Aes256Gcm::new_from_slice(&key).unwrap();
+        cipher
+            .decrypt(Nonce::from_slice(&self.nonce), self.ciphertext.as_ref())
+            .map_err(|_| "Incorrect secret".to_string())
+    }
+
+    fn to_hex(&self) -> String {
+        hex::encode([self.salt.as_slice(), self.nonce.as_slice(), self.ciphertext.as_slice()].concat())
+    }
+
+    fn from_hex(ciphertext: &str) -> Result<Self, String> {
+        let bytes = hex::decode(ciphertext).map_err(|error| error.to_string())?;
+        if bytes.len() <= SALT_LEN + NONCE_LEN {
+            return Err("Ciphertext is too short".to_string());
+        }
+
+        let mut salt = [0u8; SALT_LEN];
+        salt.copy_from_slice(&bytes[..SALT_LEN]);
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce.copy_from_slice(&bytes[SALT_LEN..SALT_LEN + NONCE_LEN]);
+        let ciphertext = bytes[SALT_LEN + NONCE_LEN..].to_vec();
+
+        Ok(Self { salt, nonce, ciphertext })
+    }
+}
+
+/// Converts a message into the field elements it will be signed over.
+///
+/// In "raw" mode (mirroring the `--raw` flag of the snarkOS `account sign` subcommand),
+/// the message bytes are length-prefixed and hashed with Blake2s-256 into a single field
+/// element, so distinct messages (including ones differing only by trailing zero bytes)
+/// never collapse to the same field representation. In "value" mode, the message is parsed
+/// as an Aleo `Value` (e.g. `100u64`, a struct literal) and its field representation is
+/// signed directly via `ToFields`.
+fn message_to_fields(message: &[u8], is_raw: bool) -> Result<Vec<Field>, String> {
+    if is_raw {
+        let mut hasher = Blake2s256::new();
+        hasher.update((message.len() as u64).to_le_bytes());
+        hasher.update(message);
+        Ok(vec![Field::from_bytes_le_mod_order(&hasher.finalize())])
+    } else {
+        let string = std::str::from_utf8(message).map_err(|error| error.to_string())?;
+        let value = Value::from_str(string).map_err(|error| error.to_string())?;
+        value.to_fields().map_err(|error| error.to_string())
+    }
+}
+
 #[wasm_bindgen]
 pub struct PrivateKey {
     pub(crate) private_key: PrivateKeyNative,
@@ -44,6 +156,29 @@ impl PrivateKey {
         Self { private_key }
     }
 
+    /// Deterministically derives a private key from a caller-supplied 32-byte seed.
+    #[wasm_bindgen]
+    pub fn from_seed(seed: &[u8]) -> Result<PrivateKey, String> {
+        let seed = <[u8; 32]>::try_from(seed).map_err(|_| "Seed must be 32 bytes".to_string())?;
+        let private_key = PrivateKeyNative::new(&mut StdRng::from_seed(seed)).map_err(|error| error.to_string())?;
+
+        Ok(Self { private_key })
+    }
+
+    /// Serializes the private key to its canonical little-endian byte encoding.
+    #[wasm_bindgen]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.private_key.to_bytes_le().unwrap()
+    }
+
+    /// Deserializes a private key from its canonical little-endian byte encoding.
+    #[wasm_bindgen]
+    pub fn from_bytes(bytes: &[u8]) -> Result<PrivateKey, String> {
+        let private_key = PrivateKeyNative::from_bytes_le(bytes).map_err(|error| error.to_string())?;
+
+        Ok(Self { private_key })
+    }
+
     #[wasm_bindgen]
     #[allow(clippy::inherent_to_string)]
     pub fn to_string(&self) -> String {
@@ -51,28 +186,73 @@ impl PrivateKey {
     }
 
     #[wasm_bindgen]
-    pub fn to_view_key(&self) -> String {
-        let view_key = ViewKey::try_from(self.private_key).unwrap();
-        view_key.to_string()
+    pub fn to_view_key(&self) -> ViewKey {
+        ViewKey::from_private_key(&self.private_key)
     }
 
     #[wasm_bindgen]
-    pub fn to_address(&self) -> String {
-        let address = Address::try_from(self.private_key).unwrap();
-        address.to_string()
+    pub fn to_address(&self) -> Address {
+        Address::from_private_key(&self.private_key)
     }
 
     #[wasm_bindgen]
     pub fn decrypt(&self, ciphertext: &str) -> Result<String, String> {
-        let view_key = ViewKey::try_from(self.private_key).map_err(|error| error.to_string())?;
-        let ciphertext = RecordCiphertext::from_str(ciphertext).map_err(|error| error.to_string())?;
-        match ciphertext.decrypt(&view_key) {
-            Ok(plaintext) => Ok(plaintext.to_string()),
-            Err(_) => Err("Incorrect view key".to_string()),
-        }
+        self.to_view_key().decrypt(ciphertext)
+    }
+
+    /// Signs a message with the private key, returning an Aleo `sign1...` signature.
+    ///
+    /// When `is_raw` is `true`, the message bytes are hashed directly (the `--raw` mode of
+    /// the snarkOS `account sign` subcommand). When `false`, the message is parsed as an
+    /// Aleo `Value` (e.g. `100u64`) and its field representation is signed.
+    #[wasm_bindgen]
+    pub fn sign(&self, message: &[u8], is_raw: bool) -> Result<String, String> {
+        let fields = message_to_fields(message, is_raw)?;
+        let signature =
+            self.private_key.sign(&fields, &mut StdRng::from_entropy()).map_err(|error| error.to_string())?;
+        Ok(signature.to_string())
+    }
+
+    /// Encrypts the private key under `secret`, for safekeeping e.g. in browser local storage.
+    #[wasm_bindgen]
+    pub fn encrypt(&self, secret: &str) -> String {
+        PrivateKeyCiphertext::encrypt(secret, self.private_key.to_string().as_bytes()).to_hex()
+    }
+
+    /// Decrypts a ciphertext produced by [`PrivateKey::encrypt`], failing if `secret` is wrong.
+    #[wasm_bindgen]
+    pub fn from_encrypted(ciphertext: &str, secret: &str) -> Result<PrivateKey, String> {
+        let plaintext = PrivateKeyCiphertext::from_hex(ciphertext)?.decrypt(secret)?;
+        let private_key = std::str::from_utf8(&plaintext)
+            .ok()
+            .and_then(|string| PrivateKeyNative::from_str(string).ok())
+            .ok_or_else(|| "Incorrect secret".to_string())?;
+
+        Ok(Self { private_key })
     }
 }
 
+/// Verifies a `sign1...` signature over a message for the given address.
+///
+/// `is_raw` must match the mode the message was originally signed with; see [`PrivateKey::sign`].
+#[wasm_bindgen]
+pub fn verify(signature: &str, address: &str, message: &[u8], is_raw: bool) -> bool {
+    let signature = match Signature::from_str(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    let address = match AddressNative::from_str(address) {
+        Ok(address) => address,
+        Err(_) => return false,
+    };
+    let fields = match message_to_fields(message, is_raw) {
+        Ok(fields) => fields,
+        Err(_) => return false,
+    };
+
+    signature.verify(&address, &fields)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,11 +282,10 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new private key.
             let private_key = PrivateKey::new();
-            let expected = Address::from_str(&private_key.to_address()).unwrap();
+            let expected = private_key.to_address().to_string();
 
             // Check the private_key derived from the view key.
-            let view_key = ViewKey::from_str(&private_key.to_view_key()).unwrap();
-            assert_eq!(expected, Address::try_from(&view_key).unwrap());
+            assert_eq!(expected, private_key.to_view_key().to_address().to_string());
         }
     }
 
@@ -127,4 +306,98 @@ mod tests {
         let plaintext = incorrect_private_key.decrypt(ciphertext);
         assert!(plaintext.is_err());
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_and_verify_raw() {
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::new();
+            let message = b"hello world";
+
+            let signature = private_key.sign(message, true).unwrap();
+            assert!(verify(&signature, &private_key.to_address().to_string(), message, true));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_and_verify_value() {
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::new();
+            let message = b"100u64";
+
+            let signature = private_key.sign(message, false).unwrap();
+            assert!(verify(&signature, &private_key.to_address().to_string(), message, false));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_raw_does_not_collide_on_trailing_zero() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address().to_string();
+
+        let signature = private_key.sign(b"A", true).unwrap();
+        assert!(verify(&signature, &address, b"A", true));
+        assert!(!verify(&signature, &address, b"A\x00", true));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_verify_fails_for_wrong_mode() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address().to_string();
+        let message = b"100u64";
+
+        let signature = private_key.sign(message, false).unwrap();
+        assert!(!verify(&signature, &address, message, true));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_verify_fails_for_wrong_address() {
+        let private_key = PrivateKey::new();
+        let other_address = PrivateKey::new().to_address().to_string();
+        let message = b"hello world";
+
+        let signature = private_key.sign(message, true).unwrap();
+        assert!(!verify(&signature, &other_address, message, true));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_encrypt_and_decrypt() {
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::new();
+            let ciphertext = private_key.encrypt("hunter2");
+
+            let decrypted = PrivateKey::from_encrypted(&ciphertext, "hunter2").unwrap();
+            assert_eq!(private_key.to_string(), decrypted.to_string());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_decrypt_with_wrong_secret_fails() {
+        let private_key = PrivateKey::new();
+        let ciphertext = private_key.encrypt("hunter2");
+
+        assert!(PrivateKey::from_encrypted(&ciphertext, "wrong-password").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_seed_is_deterministic() {
+        let seed = [42u8; 32];
+        let first = PrivateKey::from_seed(&seed).unwrap();
+        let second = PrivateKey::from_seed(&seed).unwrap();
+        assert_eq!(first.to_string(), second.to_string());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_seed_rejects_wrong_length() {
+        assert!(PrivateKey::from_seed(&[0u8; 16]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_to_bytes_and_from_bytes_roundtrip() {
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::new();
+            let bytes = private_key.to_bytes();
+            let recovered = PrivateKey::from_bytes(&bytes).unwrap();
+            assert_eq!(private_key.to_string(), recovered.to_string());
+        }
+    }
 }
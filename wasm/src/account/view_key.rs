@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Address;
+
+use aleo_account::{PrivateKey as PrivateKeyNative, RecordCiphertext, ViewKey as ViewKeyNative};
+
+use std::{convert::TryFrom, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct ViewKey {
+    pub(crate) view_key: ViewKeyNative,
+}
+
+#[wasm_bindgen]
+impl ViewKey {
+    #[wasm_bindgen]
+    pub fn from_string(view_key: &str) -> Self {
+        let view_key = ViewKeyNative::from_str(view_key).unwrap();
+
+        Self { view_key }
+    }
+
+    #[wasm_bindgen]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.view_key.to_string()
+    }
+
+    #[wasm_bindgen]
+    pub fn to_address(&self) -> Address {
+        Address::from_view_key(&self.view_key)
+    }
+
+    #[wasm_bindgen]
+    pub fn decrypt(&self, ciphertext: &str) -> Result<String, String> {
+        let ciphertext = RecordCiphertext::from_str(ciphertext).map_err(|error| error.to_string())?;
+        match ciphertext.decrypt(&self.view_key) {
+            Ok(plaintext) => Ok(plaintext.to_string()),
+            Err(_) => Err("Incorrect view key".to_string()),
+        }
+    }
+
+    /// Cheaply checks whether the record ciphertext is owned by this view key, without
+    /// running the full record decryption.
+    #[wasm_bindgen]
+    pub fn is_owner(&self, ciphertext: &str) -> bool {
+        match RecordCiphertext::from_str(ciphertext) {
+            Ok(ciphertext) => ciphertext.is_owner(&self.view_key),
+            Err(_) => false,
+        }
+    }
+
+    /// Scans a batch of record ciphertexts, decrypting only those owned by this view key.
+    ///
+    /// Ciphertexts that aren't owned by this key, or that fail to parse, decrypt to `None`
+    /// instead of paying for a full decryption.
+    #[wasm_bindgen]
+    pub fn decrypt_many(&self, ciphertexts: Vec<String>) -> Vec<Option<String>> {
+        ciphertexts
+            .into_iter()
+            .map(|ciphertext| if self.is_owner(&ciphertext) { self.decrypt(&ciphertext).ok() } else { None })
+            .collect()
+    }
+}
+
+impl ViewKey {
+    pub(crate) fn from_private_key(private_key: &PrivateKeyNative) -> Self {
+        Self { view_key: ViewKeyNative::try_from(private_key).unwrap() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+
+    use wasm_bindgen_test::*;
+
+    const ITERATIONS: u64 = 1_000;
+
+    #[wasm_bindgen_test]
+    pub fn test_view_key_from_string() {
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::new();
+            let expected = private_key.to_view_key();
+
+            assert_eq!(expected.to_string(), ViewKey::from_string(&expected.to_string()).to_string());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_address_from_view_key() {
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::new();
+            let view_key = private_key.to_view_key();
+
+            assert_eq!(private_key.to_address().to_string(), view_key.to_address().to_string());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_decrypt_success() {
+        let ciphertext = "record1qyqspplg2ud9gguy8ud9wjmee3cf2vztxcjxe2ernf8m7ru5wvsqkdqxqyqsq7y540qmemqx3675pufewwmywsudzrpstjx3fd38c6d8uz4r4mgpqqqt2q2jjczxp2y6986zdqz3mr5jmhggmge3exc72vgw2kgr4gea2zgzhrz8q";
+        let private_key = PrivateKey::from_string("APrivateKey1zkp6ka4UZu9JfMFDBqzKBffX7HQpYneKtzCTwnHb5GXVDpL");
+        let plaintext = private_key.to_view_key().decrypt(ciphertext);
+        let expected_plaintext = "{owner: aleo1snwe5h89dv6hv2q2pl3v8l9cweeuwrgejmlnwza6ndacygznlu9sjt8pgv.private, gates: 1u64.private, data: {}, _nonce: 4447510634654730534613001085815220248957154008834207042015711498717088580021group.public}";
+        assert!(plaintext.is_ok());
+        assert_eq!(expected_plaintext, plaintext.unwrap())
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_is_owner() {
+        let ciphertext = "record1qyqspplg2ud9gguy8ud9wjmee3cf2vztxcjxe2ernf8m7ru5wvsqkdqxqyqsq7y540qmemqx3675pufewwmywsudzrpstjx3fd38c6d8uz4r4mgpqqqt2q2jjczxp2y6986zdqz3mr5jmhggmge3exc72vgw2kgr4gea2zgzhrz8q";
+
+        let owner = PrivateKey::from_string("APrivateKey1zkp6ka4UZu9JfMFDBqzKBffX7HQpYneKtzCTwnHb5GXVDpL").to_view_key();
+        assert!(owner.is_owner(ciphertext));
+
+        let stranger = PrivateKey::from_string("APrivateKey1zkp6zrcYuoiMR6ePdWVLLdFehR8VMZQ2amsb8nqjLfNFanp").to_view_key();
+        assert!(!stranger.is_owner(ciphertext));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_decrypt_many() {
+        let ciphertext = "record1qyqspplg2ud9gguy8ud9wjmee3cf2vztxcjxe2ernf8m7ru5wvsqkdqxqyqsq7y540qmemqx3675pufewwmywsudzrpstjx3fd38c6d8uz4r4mgpqqqt2q2jjczxp2y6986zdqz3mr5jmhggmge3exc72vgw2kgr4gea2zgzhrz8q";
+        let owner = PrivateKey::from_string("APrivateKey1zkp6ka4UZu9JfMFDBqzKBffX7HQpYneKtzCTwnHb5GXVDpL").to_view_key();
+
+        let results = owner.decrypt_many(vec![ciphertext.to_string(), "not a ciphertext".to_string()]);
+        assert!(results[0].is_some());
+        assert!(results[1].is_none());
+    }
+}